@@ -1,9 +1,11 @@
 use super::{cstr, lstat, Dir, DirEntry, ReadDir};
 use crate::ffi::{CStr, CString};
 use crate::io;
-use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd};
+use crate::os::unix::io::{AsFd, AsRawFd, FromRawFd, IntoRawFd};
 use crate::os::unix::prelude::{BorrowedFd, OwnedFd};
 use crate::path::{Path, PathBuf};
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::{Arc, Condvar, Mutex};
 use crate::sys::{cvt, cvt_r};
 
 #[cfg(not(all(target_os = "macos", target_arch = "x86_64"),))]
@@ -61,6 +63,28 @@ pub fn openat_nofollow_dironly(parent_fd: Option<BorrowedFd<'_>>, p: &CStr) -> i
     Ok(unsafe { OwnedFd::from_raw_fd(fd) })
 }
 
+// Runs a `libc` call returning the usual `-1`-on-error convention, treating `ENOENT` as success
+// when `force` is set. Used to make removal tolerant of entries that are concurrently deleted by
+// another process, or that simply vanished between `readdir` and `unlinkat`/`openat`. Errors other
+// than `ENOENT` (`EACCES`, `EROFS`, ...) are always propagated, `force` or not.
+fn cvt_force(t: i32, force: bool) -> io::Result<()> {
+    match cvt(t) {
+        Ok(_) => Ok(()),
+        Err(err) if force && err.raw_os_error() == Some(libc::ENOENT) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+// Duplicates `fd` into an independently-owned descriptor referring to the same open file
+// description. Used by the parallel removal path, where a directory's fd must stay valid for as
+// long as any worker thread still references it, rather than only as long as the `Dir`/`ReadDir`
+// used to drain it (which closes its own fd via `closedir` as soon as that thread is done).
+fn dup_fd(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+    let new_fd = cvt(unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_DUPFD_CLOEXEC, 0) })?;
+    // SAFETY: F_DUPFD_CLOEXEC returns a freshly-allocated, owned file descriptor
+    Ok(unsafe { OwnedFd::from_raw_fd(new_fd) })
+}
+
 #[cfg(any(
     target_os = "solaris",
     target_os = "illumos",
@@ -95,19 +119,25 @@ impl OpenDir<'_> {
     // Opens the entry as a directory and returns Ok(Some(Opendir)), if parent_fd + name denotes a directory.
     // Otherwise tries to unlink and returns Ok(None) if successful. The path supposed to specify the
     // root deletion directory is not unlinked.
+    //
+    // When `force` is set, an entry that has already vanished - whether it never existed by the
+    // time we got to it, or disappeared between `readdir` and this call - is treated the same as
+    // one successfully unlinked, rather than surfacing `ENOENT` to the caller.
     fn open_or_unlink(
         parent_fd: Option<BorrowedFd<'_>>,
         name: CString,
+        force: bool,
     ) -> io::Result<Option<Self>> {
         // try to open as a directory
         let fd = match openat_nofollow_dironly(parent_fd, &name) {
             Ok(fd) => fd,
+            Err(err) if force && err.raw_os_error() == Some(libc::ENOENT) => return Ok(None),
             Err(err) if err.raw_os_error() == Some(libc::ENOTDIR) => {
                 // not a directory - unlink and return
                 return match parent_fd {
                     // unlink...
                     Some(parent_fd) => {
-                        cvt(unsafe { unlinkat(parent_fd.as_raw_fd(), name.as_ptr(), 0) })?;
+                        cvt_force(unsafe { unlinkat(parent_fd.as_raw_fd(), name.as_ptr(), 0) }, force)?;
                         Ok(None)
                     }
                     // ...unless this was supposed to be the deletion root directory
@@ -135,20 +165,39 @@ impl OpenDir<'_> {
     }
 }
 
-fn remove_dir_all_loop(p: &Path) -> io::Result<()> {
-    let mut ancestors = Vec::<OpenDir<'_>>::new();
-    let mut current = OpenDir::open_or_unlink(None, cstr(p)?)?.unwrap();
+// `ancestors` is taken by reference so that batch callers (see `remove_dir_all_many`) can reuse
+// the same buffer - and its underlying allocation - across many roots instead of paying for a
+// fresh `Vec` on every call. It is cleared on entry (in case a prior root using the same buffer
+// errored out mid-tree) and is empty again once this function returns `Ok`; an error return can
+// leave the directories that were open at that point in it.
+fn remove_dir_all_loop(
+    p: &Path,
+    force: bool,
+    ancestors: &mut Vec<OpenDir<'_>>,
+) -> io::Result<()> {
+    ancestors.clear();
+    let mut current = match OpenDir::open_or_unlink(None, cstr(p)?, force)? {
+        Some(dir) => dir,
+        // only reachable with `force` set: the root itself is already gone
+        None => return Ok(()),
+    };
+    ensure_fd_not_root_dir(current.fd)?;
     loop {
         while let Some(child) = current.readdir.next() {
             let child = child?;
             if let Some(false) = is_dir(&child) {
                 // just unlink files
-                cvt(unsafe { unlinkat(current.fd.as_raw_fd(), child.name_cstr().as_ptr(), 0) })?;
+                cvt_force(
+                    unsafe { unlinkat(current.fd.as_raw_fd(), child.name_cstr().as_ptr(), 0) },
+                    force,
+                )?;
             } else {
                 // try to open the entry as directory, unlink it if it is not
-                if let Some(child) =
-                    OpenDir::open_or_unlink(Some(current.fd), child.name_cstr().into())?
-                {
+                if let Some(child) = OpenDir::open_or_unlink(
+                    Some(current.fd),
+                    child.name_cstr().into(),
+                    force,
+                )? {
                     // recurse into the newly opened directory
                     let parent = current;
                     current = child;
@@ -160,7 +209,7 @@ fn remove_dir_all_loop(p: &Path) -> io::Result<()> {
         // unlink the directory after removing its contents
         let parent_fd =
             ancestors.last().map(|open_dir| open_dir.fd.as_raw_fd()).unwrap_or(libc::AT_FDCWD);
-        cvt(unsafe { unlinkat(parent_fd, current.name.as_ptr(), libc::AT_REMOVEDIR) })?;
+        cvt_force(unsafe { unlinkat(parent_fd, current.name.as_ptr(), libc::AT_REMOVEDIR) }, force)?;
 
         // go up to the parent directory if we are not done
         match ancestors.pop() {
@@ -170,7 +219,274 @@ fn remove_dir_all_loop(p: &Path) -> io::Result<()> {
     }
 }
 
-pub fn remove_dir_all_modern(p: &Path) -> io::Result<()> {
+// Number of worker threads used by `remove_dir_all_parallel`. Directory removal is latency-bound
+// on the round trip of each `openat`/`unlinkat` syscall rather than CPU-bound, so a small, fixed
+// pool is enough to keep several of those round trips in flight concurrently.
+const PARALLEL_WORKERS: usize = 4;
+
+/// A directory participating in a parallel removal.
+///
+/// Unlike `OpenDir`, whose `fd` is a `BorrowedFd` tied to its parent's stack frame, `SharedDir`
+/// owns its file descriptor (a duplicate, see `dup_fd`) and is reference-counted via `Arc`: an
+/// arbitrary worker thread may still need `fd` to remove this directory's children long after the
+/// thread that opened it has moved on, so the descriptor must stay open until the last such
+/// reference - held either by a child `SharedDir` or by a queued `WorkItem` - is dropped.
+struct SharedDir {
+    fd: OwnedFd,
+    name: CString,
+    parent: Option<Arc<SharedDir>>,
+    // Number of "still outstanding" references for the purpose of deciding when this directory's
+    // own `AT_REMOVEDIR` may run: one for each subdirectory opened under it that has not yet been
+    // fully removed, plus one extra placeholder held while its own entries are still being
+    // drained (released by `finish()` at the end of `process_dir`). This prevents the directory
+    // from being queued for removal while the thread draining it just hasn't found all of its
+    // subdirectories yet.
+    pending: AtomicUsize,
+}
+
+impl SharedDir {
+    fn parent_fd(&self) -> i32 {
+        self.parent.as_ref().map(|p| p.fd.as_raw_fd()).unwrap_or(libc::AT_FDCWD)
+    }
+
+    // Releases one outstanding reference. Returns `Some(self)` if that was the last one, meaning
+    // this directory is now safe to remove with `AT_REMOVEDIR`.
+    fn finish(self: &Arc<Self>) -> Option<Arc<Self>> {
+        if self.pending.fetch_sub(1, Ordering::AcqRel) == 1 {
+            Some(Arc::clone(self))
+        } else {
+            None
+        }
+    }
+}
+
+// A unit of work for the parallel removal pool: either a directory whose entries still need to be
+// drained, or one that is ready for its final `AT_REMOVEDIR` now that all children are gone.
+enum WorkItem {
+    Open(Arc<SharedDir>, ReadDir),
+    Close(Arc<SharedDir>),
+}
+
+// SAFETY: a `ReadDir` popped off the queue is only ever touched by the single worker thread
+// processing it; it is never accessed by more than one thread at a time.
+unsafe impl Send for WorkItem {}
+
+struct WorkQueue {
+    items: Mutex<Vec<WorkItem>>,
+    // Number of work items that exist but have not yet been fully processed, including ones
+    // currently being worked on. Reaching zero means the whole tree has been removed.
+    outstanding: AtomicUsize,
+    ready: Condvar,
+    error: Mutex<Option<io::Error>>,
+}
+
+impl WorkQueue {
+    fn push(&self, item: WorkItem) {
+        self.outstanding.fetch_add(1, Ordering::AcqRel);
+        self.items.lock().unwrap().push(item);
+        self.ready.notify_one();
+    }
+
+    // Captures the first error reported by any worker; later errors are discarded.
+    fn record_error(&self, err: io::Error) {
+        self.error.lock().unwrap().get_or_insert(err);
+    }
+
+    // Blocks until either work is available or every worker has run out of it, the latter meaning
+    // the whole tree has been processed.
+    fn pop(&self) -> Option<WorkItem> {
+        let mut items = self.items.lock().unwrap();
+        loop {
+            if let Some(item) = items.pop() {
+                return Some(item);
+            }
+            if self.outstanding.load(Ordering::Acquire) == 0 {
+                return None;
+            }
+            items = self.ready.wait(items).unwrap();
+        }
+    }
+
+    fn finish_one(&self) {
+        // hold the lock so a worker in `pop` cannot check `outstanding` and then miss this wakeup
+        let _items = self.items.lock().unwrap();
+        self.outstanding.fetch_sub(1, Ordering::AcqRel);
+        self.ready.notify_all();
+    }
+}
+
+// Opens `name` as a directory (relative to `parent_fd`, or cwd-relative when `parent_fd` is
+// `None`, mirroring `OpenDir::open_or_unlink`'s root handling), returning the long-lived `fd` to
+// store in a `SharedDir` alongside the `ReadDir` used to drain it. On `ENOTDIR` the entry is
+// unlinked and `None` is returned instead, unless this is the removal root.
+fn open_or_unlink_shared(
+    parent_fd: Option<BorrowedFd<'_>>,
+    name: &CStr,
+) -> io::Result<Option<(OwnedFd, ReadDir)>> {
+    let fd = match openat_nofollow_dironly(parent_fd, name) {
+        Ok(fd) => fd,
+        Err(err) if err.raw_os_error() == Some(libc::ENOTDIR) => {
+            return match parent_fd {
+                Some(parent_fd) => {
+                    cvt(unsafe { unlinkat(parent_fd.as_raw_fd(), name.as_ptr(), 0) })?;
+                    Ok(None)
+                }
+                None => Err(err),
+            };
+        }
+        Err(err) => return Err(err),
+    };
+
+    // keep an independent copy alive for the `SharedDir`; see its doc comment
+    let long_lived = dup_fd(fd.as_fd())?;
+
+    let ptr = unsafe { fdopendir(fd.as_raw_fd()) };
+    if ptr.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    let dirp = Dir(ptr);
+    // file descriptor is automatically closed by Dir::drop() now, so give up ownership; this
+    // closes `fd`, not `long_lived`, once `dirp`/the resulting `ReadDir` is dropped
+    let _ = fd.into_raw_fd();
+    let dummy_root = PathBuf::new();
+    let readdir = ReadDir::new(dirp, dummy_root);
+    Ok(Some((long_lived, readdir)))
+}
+
+// Drains `dir`'s entries: unlinking plain files inline and pushing newly-opened subdirectories as
+// new work items. Queues `dir` itself for removal once its entries are drained and it has no
+// subdirectories still pending.
+fn process_dir(queue: &WorkQueue, dir: Arc<SharedDir>, mut readdir: ReadDir) -> io::Result<()> {
+    while let Some(child) = readdir.next() {
+        let child = child?;
+        if let Some(false) = is_dir(&child) {
+            // just unlink files
+            cvt(unsafe { unlinkat(dir.fd.as_raw_fd(), child.name_cstr().as_ptr(), 0) })?;
+            continue;
+        }
+        // try to open the entry as directory, unlink it if it is not
+        let name = child.name_cstr();
+        if let Some((fd, child_readdir)) = open_or_unlink_shared(Some(dir.fd.as_fd()), name)? {
+            dir.pending.fetch_add(1, Ordering::AcqRel);
+            let child_dir = Arc::new(SharedDir {
+                fd,
+                name: name.into(),
+                parent: Some(Arc::clone(&dir)),
+                pending: AtomicUsize::new(1),
+            });
+            queue.push(WorkItem::Open(child_dir, child_readdir));
+        }
+    }
+    // release the placeholder reference held while draining; if no subdirectories are still
+    // pending, `dir` is now ready to be unlinked
+    if let Some(ready) = dir.finish() {
+        queue.push(WorkItem::Close(ready));
+    }
+    Ok(())
+}
+
+fn worker_loop(queue: &WorkQueue) {
+    while let Some(item) = queue.pop() {
+        let result = match item {
+            WorkItem::Open(dir, readdir) => process_dir(queue, dir, readdir),
+            WorkItem::Close(dir) => {
+                let result = cvt(unsafe {
+                    unlinkat(dir.parent_fd(), dir.name.as_ptr(), libc::AT_REMOVEDIR)
+                })
+                .map(drop);
+                // whether or not removal succeeded, our parent no longer needs to wait on us
+                if let Some(parent) = &dir.parent {
+                    if let Some(ready) = parent.finish() {
+                        queue.push(WorkItem::Close(ready));
+                    }
+                }
+                result
+            }
+        };
+        if let Err(err) = result {
+            queue.record_error(err);
+        }
+        queue.finish_one();
+    }
+}
+
+/// Like [`remove_dir_all_modern`], but distributes subdirectory deletion across a small pool of
+/// worker threads instead of walking the tree on the calling thread alone. Intended for large
+/// trees on storage where removal is I/O-latency bound rather than throughput bound.
+pub fn remove_dir_all_parallel(p: &Path) -> io::Result<()> {
+    // As in remove_dir_all_one(): open_or_unlink_shared() uses O_NOFOLLOW, so a symlink root has
+    // to be special-cased here rather than failing with ELOOP.
+    let attr = lstat(p)?;
+    if attr.file_type().is_symlink() {
+        return crate::fs::remove_file(p);
+    }
+
+    let name = cstr(p)?;
+    let Some((fd, readdir)) = open_or_unlink_shared(None, &name)? else {
+        unreachable!("open_or_unlink_shared(None, ..) never unlinks, only errors or opens");
+    };
+    ensure_fd_not_root_dir(fd.as_fd())?;
+    let root = Arc::new(SharedDir { fd, name, parent: None, pending: AtomicUsize::new(1) });
+
+    let queue = WorkQueue {
+        items: Mutex::new(Vec::new()),
+        outstanding: AtomicUsize::new(0),
+        ready: Condvar::new(),
+        error: Mutex::new(None),
+    };
+    queue.push(WorkItem::Open(root, readdir));
+
+    crate::thread::scope(|s| {
+        for _ in 0..PARALLEL_WORKERS {
+            s.spawn(|| worker_loop(&queue));
+        }
+    });
+
+    match queue.error.into_inner().unwrap() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+// Returns the `(st_dev, st_ino)` pair identifying the already-open directory `fd`, via `fstat`
+// rather than a second path resolution. Resolving `p` as a path a second time here would be a
+// TOCTOU race: a path component could be swapped for a symlink to "/" between that check and the
+// `openat_nofollow_dironly` call the real traversal uses, bypassing the guard entirely. `fstat`ing
+// the very fd removal is about to use closes that window.
+fn dir_identity(fd: BorrowedFd<'_>) -> io::Result<(libc::dev_t, libc::ino_t)> {
+    let mut st: libc::stat = unsafe { crate::mem::zeroed() };
+    cvt_r(|| unsafe { libc::fstat(fd.as_raw_fd(), &mut st) })?;
+    Ok((st.st_dev, st.st_ino))
+}
+
+// Returns the `(st_dev, st_ino)` pair identifying "/", the same identity GNU `rm --preserve-root`
+// compares against. Unlike `dir_identity`, there is no attacker-controlled path component leading
+// up to "/" for a symlink swap to exploit, so a plain path resolution is fine here.
+fn root_dir_identity() -> io::Result<(libc::dev_t, libc::ino_t)> {
+    let mut st: libc::stat = unsafe { crate::mem::zeroed() };
+    let root = cstr(Path::new("/"))?;
+    cvt_r(|| unsafe { libc::stat(root.as_ptr(), &mut st) })?;
+    Ok((st.st_dev, st.st_ino))
+}
+
+// Refuses to proceed if `fd` is the process's root directory ("/"), mirroring the
+// `--preserve-root` safety net GNU `rm` applies by default: without it, a caller that accidentally
+// passes "/" (or a symlink or bind mount pointing at it) would have the whole filesystem unlinked
+// out from under it. Takes the fd removal has already opened - see `dir_identity` - rather than a
+// path, so the check can't be bypassed by a race between it and the real traversal.
+fn ensure_fd_not_root_dir(fd: BorrowedFd<'_>) -> io::Result<()> {
+    if dir_identity(fd)? == root_dir_identity()? {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "refusing to remove the root directory",
+        ));
+    }
+    Ok(())
+}
+
+// Shared by `remove_dir_all_modern` and `remove_dir_all_many`: removes a single, non-`force` root,
+// reusing the caller's `ancestors` buffer.
+fn remove_dir_all_one(p: &Path, ancestors: &mut Vec<OpenDir<'_>>) -> io::Result<()> {
     // We cannot just call remove_dir_all_loop() here because that would not delete a passed
     // symlink. remove_dir_all_loop() does not descend into symlinks and does not delete p
     // if it is a file.
@@ -178,12 +494,578 @@ pub fn remove_dir_all_modern(p: &Path) -> io::Result<()> {
     if attr.file_type().is_symlink() {
         crate::fs::remove_file(p)
     } else {
-        remove_dir_all_loop(p)
+        remove_dir_all_loop(p, false, ancestors)
+    }
+}
+
+pub fn remove_dir_all_modern(p: &Path) -> io::Result<()> {
+    remove_dir_all_one(p, &mut Vec::new())
+}
+
+/// Removes every path in `paths`, reusing one internal traversal buffer across all of them instead
+/// of re-entering the syscall machinery from scratch for each root. Every root is attempted even
+/// if an earlier one fails; the first error encountered (if any) is returned once all have been
+/// processed.
+pub fn remove_dir_all_many<'a>(paths: impl IntoIterator<Item = &'a Path>) -> io::Result<()> {
+    let mut ancestors = Vec::<OpenDir<'_>>::new();
+    let mut first_error = None;
+    for p in paths {
+        if let Err(err) = remove_dir_all_one(p, &mut ancestors) {
+            first_error.get_or_insert(err);
+        }
+    }
+    match first_error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Like [`remove_dir_all_modern`], but tolerant of the tree being concurrently mutated: any entry
+/// that is missing by the time we get to it - because it never existed, or another process removed
+/// it first - is treated as already removed instead of surfacing `ENOENT`. Errors other than
+/// `ENOENT` are still returned as usual.
+pub fn remove_dir_all_force(p: &Path) -> io::Result<()> {
+    let attr = match lstat(p) {
+        Ok(attr) => attr,
+        Err(err) if err.raw_os_error() == Some(libc::ENOENT) => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    if attr.file_type().is_symlink() {
+        match crate::fs::remove_file(p) {
+            Ok(()) => Ok(()),
+            Err(err) if err.raw_os_error() == Some(libc::ENOENT) => Ok(()),
+            Err(err) => Err(err),
+        }
+    } else {
+        remove_dir_all_loop(p, true, &mut Vec::new())
+    }
+}
+
+// Linux-only fast path that replaces the per-entry `readdir`/`unlinkat` round trips with raw
+// `getdents64` reads and `unlinkat` calls batched through `io_uring`. Feature-detected at runtime,
+// mirroring the weak-symbol probing `macos_weak` already does for `openat`: the very first
+// `io_uring` submission this process makes doubles as the probe, and a kernel that rejects it
+// (too old for `io_uring`, or for `IORING_OP_UNLINKAT` specifically, added in Linux 5.11) causes a
+// clean fall-back to `remove_dir_all_modern` instead of an error.
+#[cfg(target_os = "linux")]
+mod linux_uring {
+    use super::{cstr, cvt, ensure_fd_not_root_dir, lstat, openat_nofollow_dironly};
+    use crate::ffi::{CStr, CString};
+    use crate::io;
+    use crate::mem;
+    use crate::os::unix::io::{AsFd, AsRawFd, FromRawFd};
+    use crate::os::unix::prelude::{BorrowedFd, OwnedFd};
+    use crate::path::Path;
+    use crate::ptr;
+    use crate::sync::atomic::{AtomicU8, Ordering};
+
+    // Cached outcome of the capability probe described above this module: 0 = not yet
+    // known, 1 = `io_uring` + `IORING_OP_UNLINKAT` work, 2 = fall back to `remove_dir_all_modern`.
+    static URING_SUPPORT: AtomicU8 = AtomicU8::new(SUPPORT_UNKNOWN);
+
+    const SUPPORT_UNKNOWN: u8 = 0;
+    const SUPPORT_AVAILABLE: u8 = 1;
+    const SUPPORT_UNAVAILABLE: u8 = 2;
+
+    const IORING_OP_UNLINKAT: u8 = 36;
+    const IORING_ENTER_GETEVENTS: u32 = 1 << 0;
+    const IORING_FEAT_SINGLE_MMAP: u32 = 1 << 0;
+    const IORING_OFF_SQ_RING: i64 = 0;
+    const IORING_OFF_CQ_RING: i64 = 0x8000000;
+    const IORING_OFF_SQES: i64 = 0x10000000;
+    // Small and fixed, like `PARALLEL_WORKERS` for the thread-pool path: just enough in-flight
+    // unlinks to hide syscall latency without the bookkeeping of a dynamically-sized ring.
+    const QUEUE_DEPTH: u32 = 128;
+
+    // At or below this directory size, standing up a ring (`io_uring_setup` plus two or three
+    // `mmap`s) costs more than the handful of per-entry `unlinkat`s it would save, so
+    // `try_remove_dir_all` leaves a root directory that small to the ordinary
+    // `remove_dir_all_loop`. Only the root's size is checked; subdirectories are not. Not an
+    // exact entry count - on the common filesystems where this fast path matters (ext4, tmpfs,
+    // ...) directory size scales with the number of entries, which is all a cheap heuristic needs.
+    const MIN_DIR_SIZE_FOR_URING: u64 = 4096;
+
+    // Fixed header layout of `struct linux_dirent64` (see the `getdents64(2)` man page): 8-byte
+    // `d_ino`, 8-byte `d_off`, 2-byte `d_reclen`, 1-byte `d_type`, then the NUL-terminated
+    // `d_name`. Read via raw offsets rather than a `#[repr(C)]` struct to sidestep that struct's
+    // trailing alignment padding, which would not match the kernel's packed wire layout.
+    const DIRENT64_RECLEN_OFFSET: usize = 16;
+    const DIRENT64_TYPE_OFFSET: usize = 18;
+    const DIRENT64_NAME_OFFSET: usize = 19;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SqringOffsets {
+        head: u32,
+        tail: u32,
+        ring_mask: u32,
+        ring_entries: u32,
+        flags: u32,
+        dropped: u32,
+        array: u32,
+        resv1: u32,
+        resv2: u64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct CqringOffsets {
+        head: u32,
+        tail: u32,
+        ring_mask: u32,
+        ring_entries: u32,
+        overflow: u32,
+        cqes: u32,
+        flags: u32,
+        resv1: u32,
+        resv2: u64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Params {
+        sq_entries: u32,
+        cq_entries: u32,
+        flags: u32,
+        sq_thread_cpu: u32,
+        sq_thread_idle: u32,
+        features: u32,
+        wq_fd: u32,
+        resv: [u32; 3],
+        sq_off: SqringOffsets,
+        cq_off: CqringOffsets,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Sqe {
+        opcode: u8,
+        flags: u8,
+        ioprio: u16,
+        fd: i32,
+        off: u64,
+        addr: u64,
+        len: u32,
+        op_flags: u32,
+        user_data: u64,
+        buf_index: u16,
+        personality: u16,
+        splice_fd_in: i32,
+        pad: [u64; 2],
+    }
+
+    #[repr(C)]
+    struct Cqe {
+        user_data: u64,
+        res: i32,
+        flags: u32,
+    }
+
+    fn raw_is_dir(d_type: u8) -> Option<bool> {
+        match d_type {
+            libc::DT_UNKNOWN => None,
+            libc::DT_DIR => Some(true),
+            _ => Some(false),
+        }
+    }
+
+    fn getdents64(fd: i32, buf: &mut [u8]) -> io::Result<usize> {
+        let n = cvt(unsafe {
+            libc::syscall(libc::SYS_getdents64, fd, buf.as_mut_ptr(), buf.len()) as i32
+        })?;
+        Ok(n as usize)
+    }
+
+    fn looks_unsupported(errno: i32) -> bool {
+        matches!(errno, libc::EINVAL | libc::EOPNOTSUPP | libc::ENOSYS)
+    }
+
+    unsafe fn mmap_at(fd: i32, offset: i64, len: usize) -> io::Result<*mut u8> {
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED | libc::MAP_POPULATE,
+                fd,
+                offset,
+            )
+        };
+        if ptr == libc::MAP_FAILED { Err(io::Error::last_os_error()) } else { Ok(ptr as *mut u8) }
+    }
+
+    // Raw `getdents64`-based replacement for `ReadDir`, buffering and re-filling as it is drained.
+    struct RawReadDir {
+        fd: i32,
+        buf: Box<[u8]>,
+        len: usize,
+        pos: usize,
+    }
+
+    impl RawReadDir {
+        fn new(fd: i32) -> Self {
+            Self { fd, buf: vec![0u8; 32 * 1024].into_boxed_slice(), len: 0, pos: 0 }
+        }
+
+        // Returns the next entry's `(d_type, name)`, skipping `.` and `..`; `Ok(None)` once the
+        // directory is fully drained.
+        fn next(&mut self) -> io::Result<Option<(u8, CString)>> {
+            loop {
+                if self.pos >= self.len {
+                    let n = getdents64(self.fd, &mut self.buf)?;
+                    if n == 0 {
+                        return Ok(None);
+                    }
+                    self.len = n;
+                    self.pos = 0;
+                }
+                let reclen = u16::from_ne_bytes([
+                    self.buf[self.pos + DIRENT64_RECLEN_OFFSET],
+                    self.buf[self.pos + DIRENT64_RECLEN_OFFSET + 1],
+                ]) as usize;
+                let d_type = self.buf[self.pos + DIRENT64_TYPE_OFFSET];
+                // SAFETY: the kernel NUL-terminates `d_name` within the record it just wrote.
+                let name = unsafe {
+                    CStr::from_ptr(self.buf.as_ptr().add(self.pos + DIRENT64_NAME_OFFSET).cast())
+                }
+                .to_owned();
+                self.pos += reclen;
+                if name.as_bytes() != b"." && name.as_bytes() != b".." {
+                    return Ok(Some((d_type, name)));
+                }
+            }
+        }
+    }
+
+    // Like `OpenDir`, but backed by `RawReadDir` instead of libc's `readdir`. No `fdopendir()`
+    // call is needed for `getdents64`, so unlike `OpenDir` the fd here is a plain, fully-owned
+    // `OwnedFd` rather than one borrowed from a `Dir`'s `closedir`-on-drop.
+    struct UringDir {
+        readdir: RawReadDir,
+        fd: OwnedFd,
+        name: CString,
+    }
+
+    impl UringDir {
+        fn open_or_unlink(parent_fd: Option<BorrowedFd<'_>>, name: CString) -> io::Result<Option<Self>> {
+            let fd = match openat_nofollow_dironly(parent_fd, &name) {
+                Ok(fd) => fd,
+                Err(err) if err.raw_os_error() == Some(libc::ENOTDIR) => {
+                    return match parent_fd {
+                        Some(parent_fd) => {
+                            cvt(unsafe { libc::unlinkat(parent_fd.as_raw_fd(), name.as_ptr(), 0) })?;
+                            Ok(None)
+                        }
+                        None => Err(err),
+                    };
+                }
+                Err(err) => return Err(err),
+            };
+            let readdir = RawReadDir::new(fd.as_raw_fd());
+            Ok(Some(Self { readdir, fd, name }))
+        }
+    }
+
+    // A minimal `io_uring` submission/completion ring used only to batch `unlinkat` calls; no
+    // other opcode is ever submitted.
+    struct Ring {
+        ring_fd: OwnedFd,
+        sq_mmap: *mut u8,
+        sq_mmap_len: usize,
+        cq_mmap: *mut u8,
+        cq_mmap_len: usize,
+        sqes: *mut Sqe,
+        sqes_len: usize,
+        sq_off: SqringOffsets,
+        cq_off: CqringOffsets,
+        sq_entries: u32,
+        cq_entries: u32,
+        sq_tail: u32,
+        pending: u32,
+        // Keeps each queued unlink's path alive from `try_queue_unlink` until `drain` has
+        // confirmed the kernel is done reading it - `Sqe::addr` is a raw pointer into it, and
+        // `io_uring` only reads that pointer when the submission is actually consumed, which may
+        // be well after the call that queued it returns.
+        pending_names: Vec<CString>,
+    }
+
+    impl Ring {
+        fn new() -> io::Result<Self> {
+            let mut params: Params = unsafe { mem::zeroed() };
+            let raw_fd = cvt(unsafe {
+                libc::syscall(libc::SYS_io_uring_setup, QUEUE_DEPTH, &mut params as *mut Params)
+                    as i32
+            })?;
+            // SAFETY: a successful `io_uring_setup` returns a freshly-allocated, owned descriptor
+            let ring_fd = unsafe { OwnedFd::from_raw_fd(raw_fd) };
+
+            let sq_ring_len = params.sq_off.array as usize
+                + params.sq_entries as usize * mem::size_of::<u32>();
+            let cq_ring_len =
+                params.cq_off.cqes as usize + params.cq_entries as usize * mem::size_of::<Cqe>();
+            let sqes_len = params.sq_entries as usize * mem::size_of::<Sqe>();
+            let single_mmap = params.features & IORING_FEAT_SINGLE_MMAP != 0;
+
+            let sq_mmap_len = if single_mmap { sq_ring_len.max(cq_ring_len) } else { sq_ring_len };
+            // SAFETY: `ring_fd` was just returned by `io_uring_setup`, sized per its own `params`
+            let sq_mmap = unsafe { mmap_at(ring_fd.as_raw_fd(), IORING_OFF_SQ_RING, sq_mmap_len)? };
+
+            // From here on a later mapping can still fail, so any mapping already made above must
+            // be torn down again on the way out instead of leaking - `?` alone would skip straight
+            // past `Drop for Ring`, which only runs once a `Ring` is actually constructed.
+            let cq_mmap_result = if single_mmap {
+                Ok((sq_mmap, 0))
+            } else {
+                // SAFETY: see above
+                unsafe { mmap_at(ring_fd.as_raw_fd(), IORING_OFF_CQ_RING, cq_ring_len) }
+                    .map(|mmap| (mmap, cq_ring_len))
+            };
+            let (cq_mmap, cq_mmap_len) = match cq_mmap_result {
+                Ok(mapped) => mapped,
+                Err(err) => {
+                    unsafe { libc::munmap(sq_mmap.cast(), sq_mmap_len) };
+                    return Err(err);
+                }
+            };
+
+            // SAFETY: see above
+            let sqes = match unsafe { mmap_at(ring_fd.as_raw_fd(), IORING_OFF_SQES, sqes_len) } {
+                Ok(mapped) => mapped as *mut Sqe,
+                Err(err) => {
+                    unsafe {
+                        if cq_mmap != sq_mmap {
+                            libc::munmap(cq_mmap.cast(), cq_mmap_len);
+                        }
+                        libc::munmap(sq_mmap.cast(), sq_mmap_len);
+                    }
+                    return Err(err);
+                }
+            };
+
+            Ok(Self {
+                ring_fd,
+                sq_mmap,
+                sq_mmap_len,
+                cq_mmap,
+                cq_mmap_len,
+                sqes,
+                sqes_len,
+                sq_off: params.sq_off,
+                cq_off: params.cq_off,
+                sq_entries: params.sq_entries,
+                cq_entries: params.cq_entries,
+                sq_tail: 0,
+                pending: 0,
+                pending_names: Vec::new(),
+            })
+        }
+
+        // Queues `unlinkat(dirfd, name, 0)`, taking ownership of `name` until the submission
+        // completes. Returns `Err(name)` if the ring is full; the caller should `drain()` and
+        // retry with the same name.
+        fn try_queue_unlink(&mut self, dirfd: i32, name: CString) -> Result<(), CString> {
+            if self.pending >= self.sq_entries {
+                return Err(name);
+            }
+            let index = self.sq_tail % self.sq_entries;
+            let addr = name.as_ptr() as u64;
+            self.pending_names.push(name);
+            // SAFETY: `index` is within the `sqes` mapping, sized for `sq_entries` above
+            unsafe {
+                let sqe = &mut *self.sqes.add(index as usize);
+                *sqe = mem::zeroed();
+                sqe.opcode = IORING_OP_UNLINKAT;
+                sqe.fd = dirfd;
+                sqe.addr = addr;
+                let array = self.sq_mmap.add(self.sq_off.array as usize) as *mut u32;
+                *array.add(index as usize) = index;
+            }
+            self.sq_tail = self.sq_tail.wrapping_add(1);
+            self.pending += 1;
+            Ok(())
+        }
+
+        // Submits all queued unlinks and waits for them to complete. `is_first` marks this
+        // process's very first flush: if the kernel rejects it outright (too old for `io_uring`,
+        // or missing `IORING_OP_UNLINKAT`), none of the queued unlinks has run, so it is safe to
+        // report that back as `ErrorKind::Unsupported` for the caller to fall back on; later
+        // failures are always real removal errors.
+        fn drain(&mut self, is_first: bool) -> io::Result<()> {
+            if self.pending == 0 {
+                return Ok(());
+            }
+            let to_submit = self.pending;
+            // SAFETY: `tail` is within the `sq_mmap` mapping
+            unsafe {
+                let tail = self.sq_mmap.add(self.sq_off.tail as usize) as *mut u32;
+                ptr::write_volatile(tail, self.sq_tail);
+            }
+            let enter_result = cvt(unsafe {
+                libc::syscall(
+                    libc::SYS_io_uring_enter,
+                    self.ring_fd.as_raw_fd(),
+                    to_submit,
+                    to_submit,
+                    IORING_ENTER_GETEVENTS,
+                    ptr::null::<libc::sigset_t>(),
+                    0usize,
+                ) as i32
+            });
+            self.pending = 0;
+            if let Err(err) = enter_result {
+                let errno = err.raw_os_error().unwrap_or(0);
+                return if is_first && looks_unsupported(errno) {
+                    Err(io::Error::new(io::ErrorKind::Unsupported, err))
+                } else {
+                    Err(err)
+                };
+            }
+
+            let mut first_errno = None;
+            // SAFETY: `head`/`tail`/`cqes` are within the `cq_mmap` mapping
+            unsafe {
+                let head_ptr = self.cq_mmap.add(self.cq_off.head as usize) as *mut u32;
+                let tail_ptr = self.cq_mmap.add(self.cq_off.tail as usize) as *const u32;
+                let cqes = self.cq_mmap.add(self.cq_off.cqes as usize) as *const Cqe;
+                let mask = self.cq_entries - 1;
+                let mut head = ptr::read_volatile(head_ptr);
+                let tail = ptr::read_volatile(tail_ptr);
+                while head != tail {
+                    let cqe = &*cqes.add((head & mask) as usize);
+                    if cqe.res < 0 && first_errno.is_none() {
+                        first_errno = Some(-cqe.res);
+                    }
+                    head = head.wrapping_add(1);
+                }
+                ptr::write_volatile(head_ptr, head);
+            }
+            // the kernel has now read every queued path, so it's safe to drop them
+            self.pending_names.clear();
+            match first_errno {
+                Some(errno) if is_first && looks_unsupported(errno) => {
+                    Err(io::Error::new(io::ErrorKind::Unsupported, io::Error::from_raw_os_error(errno)))
+                }
+                Some(errno) => Err(io::Error::from_raw_os_error(errno)),
+                None => Ok(()),
+            }
+        }
+    }
+
+    impl Drop for Ring {
+        fn drop(&mut self) {
+            unsafe {
+                libc::munmap(self.sqes.cast(), self.sqes_len);
+                if self.cq_mmap != self.sq_mmap {
+                    libc::munmap(self.cq_mmap.cast(), self.cq_mmap_len);
+                }
+                libc::munmap(self.sq_mmap.cast(), self.sq_mmap_len);
+            }
+        }
+    }
+
+    fn remove_dir_all_uring(p: &Path, ring: &mut Ring) -> io::Result<()> {
+        let attr = lstat(p)?;
+        if attr.file_type().is_symlink() {
+            return crate::fs::remove_file(p);
+        }
+
+        let mut ancestors = Vec::<UringDir>::new();
+        let mut current = UringDir::open_or_unlink(None, cstr(p)?)?.unwrap();
+        ensure_fd_not_root_dir(current.fd.as_fd())?;
+        // Whether the kernel supports `io_uring` + `IORING_OP_UNLINKAT` at all is still unknown
+        // process-wide, not just for this call - gating on the shared flag (rather than a flag
+        // local to this call) means a later call, once support is confirmed, never misreads a
+        // genuine per-file `unlinkat` failure as "kernel too old" and silently falls back.
+        let mut flush = |ring: &mut Ring| -> io::Result<()> {
+            let is_first = URING_SUPPORT.load(Ordering::Relaxed) == SUPPORT_UNKNOWN;
+            let submitted = ring.pending > 0;
+            ring.drain(is_first)?;
+            // a flush that reached the kernel and succeeded confirms support, so only the very
+            // first submission is ever treated as the probe
+            if submitted {
+                URING_SUPPORT.store(SUPPORT_AVAILABLE, Ordering::Relaxed);
+            }
+            Ok(())
+        };
+        loop {
+            while let Some((d_type, name)) = current.readdir.next()? {
+                match raw_is_dir(d_type) {
+                    Some(false) => {
+                        let mut name = name;
+                        while let Err(rejected) = ring.try_queue_unlink(current.fd.as_raw_fd(), name)
+                        {
+                            flush(ring)?;
+                            name = rejected;
+                        }
+                    }
+                    // a directory, or unknown type - let `open_or_unlink` sort it out, same as
+                    // the other traversal loops in this file
+                    _ => {
+                        if let Some(child) = UringDir::open_or_unlink(Some(current.fd.as_fd()), name)? {
+                            // all of this directory's queued unlinks must land before we descend,
+                            // so that nothing is still "pending" against `current.fd` once we
+                            // stop touching it
+                            flush(ring)?;
+                            let parent = current;
+                            current = child;
+                            ancestors.push(parent);
+                        }
+                    }
+                }
+            }
+            flush(ring)?;
+
+            let parent_fd = ancestors.last().map(|dir| dir.fd.as_raw_fd()).unwrap_or(libc::AT_FDCWD);
+            cvt(unsafe { libc::unlinkat(parent_fd, current.name.as_ptr(), libc::AT_REMOVEDIR) })?;
+
+            match ancestors.pop() {
+                Some(parent) => current = parent,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Attempts the `io_uring` fast path for `p`. Returns `None` if this kernel doesn't support
+    /// it, or if `p` is a directory no larger than `MIN_DIR_SIZE_FOR_URING`, in which case the
+    /// caller should fall back to [`super::remove_dir_all_modern`]; `Some` carries the real
+    /// result otherwise.
+    pub(super) fn try_remove_dir_all(p: &Path) -> Option<io::Result<()>> {
+        if URING_SUPPORT.load(Ordering::Relaxed) == SUPPORT_UNAVAILABLE {
+            return None;
+        }
+        // A failure here (e.g. ENOENT) is a real error, not a reason to skip the fast path - leave
+        // it for `remove_dir_all_uring`/`remove_dir_all_modern` to surface properly below.
+        if let Ok(attr) = lstat(p) {
+            if !attr.file_type().is_symlink() && attr.size() <= MIN_DIR_SIZE_FOR_URING {
+                return None;
+            }
+        }
+        let mut ring = match Ring::new() {
+            Ok(ring) => ring,
+            Err(_) => {
+                URING_SUPPORT.store(SUPPORT_UNAVAILABLE, Ordering::Relaxed);
+                return None;
+            }
+        };
+        match remove_dir_all_uring(p, &mut ring) {
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                URING_SUPPORT.store(SUPPORT_UNAVAILABLE, Ordering::Relaxed);
+                None
+            }
+            result => {
+                URING_SUPPORT.store(SUPPORT_AVAILABLE, Ordering::Relaxed);
+                Some(result)
+            }
+        }
     }
 }
 
 #[cfg(not(all(target_os = "macos", target_arch = "x86_64")))]
 pub fn remove_dir_all(p: &Path) -> io::Result<()> {
+    #[cfg(target_os = "linux")]
+    if let Some(result) = linux_uring::try_remove_dir_all(p) {
+        return result;
+    }
     remove_dir_all_modern(p)
 }
 
@@ -197,3 +1079,95 @@ pub fn remove_dir_all(p: &Path) -> io::Result<()> {
         crate::sys_common::fs::remove_dir_all(p)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs;
+    use crate::sync::atomic::AtomicU32;
+
+    // Self-contained stand-in for the `tmpdir()` helper the rest of libstd's test suite shares:
+    // a fresh, empty directory under `env::temp_dir()`, unique per call so concurrently-running
+    // tests never collide.
+    fn tmpdir() -> PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = crate::env::temp_dir()
+            .join(format!("rust-dir_fd-test-{}-{}", crate::process::id(), id));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn make_tree(root: &Path) {
+        fs::create_dir_all(root.join("a/b")).unwrap();
+        fs::write(root.join("a/b/file"), b"contents").unwrap();
+        fs::write(root.join("top-level-file"), b"contents").unwrap();
+    }
+
+    #[test]
+    fn remove_dir_all_parallel_removes_whole_tree() {
+        let root = tmpdir();
+        make_tree(&root);
+        remove_dir_all_parallel(&root).unwrap();
+        assert_eq!(fs::metadata(&root).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn remove_dir_all_force_tolerates_missing_root() {
+        let root = tmpdir();
+        fs::remove_dir(&root).unwrap();
+        remove_dir_all_force(&root).unwrap();
+    }
+
+    #[test]
+    fn remove_dir_all_force_tolerates_entry_removed_during_walk() {
+        // Nothing actually races here: `a/b/file` is removed before the walk starts, so `readdir`
+        // never yields it and no `ENOENT` reaches `open_or_unlink`/`cvt_force`. This checks that
+        // force mode removes what is left of a tree that lost an entry before the call.
+        let root = tmpdir();
+        make_tree(&root);
+        fs::remove_file(root.join("a/b/file")).unwrap();
+        remove_dir_all_force(&root).unwrap();
+        assert_eq!(fs::metadata(&root).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn ensure_fd_not_root_dir_refuses_the_filesystem_root() {
+        let root_fd = openat_nofollow_dironly(None, &cstr(Path::new("/")).unwrap()).unwrap();
+        let err = ensure_fd_not_root_dir(root_fd.as_fd()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn remove_dir_all_many_continues_past_a_failing_root() {
+        let ok_root = tmpdir();
+        make_tree(&ok_root);
+        let missing_root = ok_root.join("does-not-exist");
+
+        let paths = [missing_root.as_path(), ok_root.as_path()];
+        let err = remove_dir_all_many(paths).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        // the failing root didn't stop the rest of the batch from being processed
+        assert_eq!(fs::metadata(&ok_root).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn remove_dir_all_uses_uring_fast_path_or_falls_back_cleanly() {
+        // Exercises `try_remove_dir_all` end-to-end: on kernels with `io_uring` +
+        // `IORING_OP_UNLINKAT` support it drives the real fast path, and on kernels without it,
+        // `Ring::drain`'s `ErrorKind::Unsupported` signal sends this straight back to
+        // `remove_dir_all_modern` - either way the whole tree must come out fully removed, and a
+        // directory past `MIN_DIR_SIZE_FOR_URING` is used so the fast path is actually attempted
+        // rather than short-circuited by the small-directory heuristic.
+        let root = tmpdir();
+        for i in 0..256 {
+            fs::write(root.join(format!("file-{i}")), b"x").unwrap();
+        }
+        fs::create_dir_all(root.join("subdir")).unwrap();
+        fs::write(root.join("subdir/nested-file"), b"x").unwrap();
+
+        remove_dir_all(&root).unwrap();
+        assert_eq!(fs::metadata(&root).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}